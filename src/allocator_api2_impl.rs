@@ -0,0 +1,78 @@
+//! Adapter implementing the stable-Rust `allocator_api2::Allocator` shim on
+//! top of `SlabAllocator`, gated behind the `allocator-api2` feature.
+//!
+//! This lets a `SlabAllocator<T, N>` back standard containers (`Vec`,
+//! `BTreeMap`, ...) as long as every value they allocate fits within a
+//! single cell, i.e. its `Layout` is no larger and no more aligned than `T`.
+//! Layouts that don't fit are rejected with `AllocError` rather than
+//! silently falling back to the global allocator.
+//!
+//! # Moves
+//!
+//! `Allocator` is implemented on the owned `SlabAllocator` itself. Each
+//! chunk's cells live in their own heap allocation, so handing a
+//! `SlabAllocator` by value to a container (e.g. `Vec::new_in(slab)`) moves
+//! only the chunk headers and leaves every pointer the allocator has already
+//! handed out valid. The same does not hold for `Box`es from
+//! `boxed`/`try_boxed`: each points at its chunk, and the inline chunks
+//! (`[Chunk<T, B>; N]`) move with the `SlabAllocator`. A container can also
+//! borrow the allocator through `allocator_api2`'s blanket `Allocator for &A`
+//! impl: `Vec::new_in(&slab)`.
+use crate::{Bitmap, Chunk, SlabAllocator};
+use allocator_api2::alloc::{AllocError, Allocator};
+use core::alloc::Layout;
+use core::ptr::NonNull;
+
+unsafe impl<T, const N: usize, B: Bitmap> Allocator for SlabAllocator<T, N, B> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if layout.size() > core::mem::size_of::<T>() || layout.align() > core::mem::align_of::<T>()
+        {
+            return Err(AllocError);
+        }
+
+        let chunk_offset = self.find_chunk_with_space().ok_or(AllocError)?;
+        let chunk = self.borrow_chunk(chunk_offset).ok_or(AllocError)?;
+        let cell_offset = chunk.alloc_bit().ok_or(AllocError)?;
+
+        if chunk.full() {
+            self.set_summary_bit(chunk_offset, false);
+        }
+
+        let cell_ptr = chunk.cell_ptr(cell_offset) as *mut u8;
+        let slice_ptr = core::ptr::slice_from_raw_parts_mut(cell_ptr, layout.size());
+
+        NonNull::new(slice_ptr).ok_or(AllocError)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, _layout: Layout) {
+        if let Some((chunk_offset, cell_offset)) = self.locate_cell(ptr.as_ptr() as *const T) {
+            if let Some(chunk) = self.borrow_chunk(chunk_offset) {
+                chunk.dealloc_bit(cell_offset);
+                self.set_summary_bit(chunk_offset, true);
+            }
+        }
+    }
+}
+
+impl<T, const N: usize, B: Bitmap> SlabAllocator<T, N, B> {
+    /// Finds the `(chunk, cell)` offsets whose backing storage contains
+    /// `ptr`, used to recover bookkeeping state from the raw pointers handed
+    /// back through the `Allocator` interface.
+    fn locate_cell(&self, ptr: *const T) -> Option<(usize, usize)> {
+        let stride = Chunk::<T, B>::cell_stride();
+
+        for chunk_offset in 0..self.chunk_count() {
+            let chunk = self.borrow_chunk(chunk_offset)?;
+            let base = chunk.cell_ptr(0) as usize;
+            let end = base + B::CAPACITY * stride;
+            let addr = ptr as usize;
+
+            if addr >= base && addr < end {
+                let cell_offset = (addr - base) / stride;
+                return Some((chunk_offset, cell_offset));
+            }
+        }
+
+        None
+    }
+}
@@ -2,20 +2,164 @@
 
 extern crate alloc;
 
+#[cfg(feature = "allocator-api2")]
+mod allocator_api2_impl;
+
+/// Reasons a fallible allocation on a `SlabAllocator` can fail, mirroring
+/// the `try_*` convention used by `alloc` (e.g. `Vec::try_reserve`) so
+/// callers on memory-constrained targets can tell *why* an allocation
+/// didn't happen instead of only that it didn't.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllocError {
+    /// No free cell was available in any chunk.
+    Exhausted,
+}
+
+/// The free-list tracked by each [`Chunk`], generalized over its bit width
+/// so a chunk can be backed by a `u8` for a handful of cells or by a wider
+/// word (or several) for larger fan-out, instead of always paying for
+/// `usize::BITS` cells whether they're needed or not.
+///
+/// Implementors own both the bit storage and the logic to search it, so
+/// that `alloc_bits`/`dealloc_bits` can pick whatever bit ordering and
+/// scanning strategy fits their width (a single `leading_zeros` for a
+/// primitive integer, a per-word scan for a multi-word bitmap).
+pub trait Bitmap: Copy {
+    /// The number of cells a chunk backed by this bitmap holds.
+    const CAPACITY: usize;
+
+    /// The value representing an entirely free bitmap.
+    const DEFAULT: Self;
+
+    /// Finds and marks the first free bit, returning its index, or `None`
+    /// if every bit is already allocated.
+    fn alloc_bits(&mut self) -> Option<usize>;
+
+    /// Marks the bit at `index` free again.
+    fn dealloc_bits(&mut self, index: usize);
+
+    /// True if every bit is allocated.
+    fn is_full(&self) -> bool;
+
+    /// True if every bit is free.
+    fn is_empty(&self) -> bool;
+
+    /// The number of free bits remaining.
+    fn free_count(&self) -> usize;
+}
+
+macro_rules! impl_bitmap_for_uint {
+    ($ty:ty) => {
+        impl Bitmap for $ty {
+            const CAPACITY: usize = <$ty>::BITS as usize;
+            const DEFAULT: Self = <$ty>::MAX;
+
+            fn alloc_bits(&mut self) -> Option<usize> {
+                let leading_zeros = self.leading_zeros() as usize;
+                if leading_zeros == Self::CAPACITY {
+                    return None;
+                }
+
+                let shift = (Self::CAPACITY - 1) - leading_zeros;
+                *self &= !(1 << shift);
+                Some(leading_zeros)
+            }
+
+            fn dealloc_bits(&mut self, index: usize) {
+                let shift = (Self::CAPACITY - 1) - index;
+                *self |= 1 << shift;
+            }
+
+            fn is_full(&self) -> bool {
+                *self == 0
+            }
+
+            fn is_empty(&self) -> bool {
+                *self == Self::DEFAULT
+            }
+
+            fn free_count(&self) -> usize {
+                self.count_ones() as usize
+            }
+        }
+    };
+}
+
+impl_bitmap_for_uint!(u8);
+impl_bitmap_for_uint!(u16);
+impl_bitmap_for_uint!(u32);
+impl_bitmap_for_uint!(u64);
+impl_bitmap_for_uint!(usize);
+
+/// An 8-cell chunk bitmap, for tiny pools.
+pub type Bitmap8 = u8;
+/// A 16-cell chunk bitmap.
+pub type Bitmap16 = u16;
+/// A 32-cell chunk bitmap.
+pub type Bitmap32 = u32;
+/// A 64-cell chunk bitmap. The default used by [`SlabAllocator`], matching
+/// this crate's original fixed `usize::BITS`-per-chunk behavior on the
+/// common 64-bit target.
+pub type Bitmap64 = u64;
+
+/// A bitmap spanning `WORDS` `u64` words, for fan-out wider than a single
+/// machine word allows. Allocation recurses the same `leading_zeros` trick
+/// `SlabAllocator`'s own summary bitmap uses, one tier down: scan words for
+/// one that isn't full, then find the free bit within it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WideBitmap<const WORDS: usize>([u64; WORDS]);
+
+impl<const WORDS: usize> Bitmap for WideBitmap<WORDS> {
+    const CAPACITY: usize = WORDS * u64::BITS as usize;
+    const DEFAULT: Self = WideBitmap([u64::MAX; WORDS]);
+
+    fn alloc_bits(&mut self) -> Option<usize> {
+        for (word_idx, word) in self.0.iter_mut().enumerate() {
+            if let Some(bit_idx) = word.alloc_bits() {
+                return Some(word_idx * u64::BITS as usize + bit_idx);
+            }
+        }
+
+        None
+    }
+
+    fn dealloc_bits(&mut self, index: usize) {
+        let word_idx = index / u64::BITS as usize;
+        let bit_idx = index % u64::BITS as usize;
+        self.0[word_idx].dealloc_bits(bit_idx);
+    }
+
+    fn is_full(&self) -> bool {
+        self.0.iter().all(|word| word.is_full())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.iter().all(|word| word.is_empty())
+    }
+
+    fn free_count(&self) -> usize {
+        self.0.iter().map(|word| word.free_count()).sum()
+    }
+}
+
 /// A custom, and minimal, `Box`-like implementation for the time being. This
 /// is acting as a placeholder until the allocator api stabilizes.
 ///
 /// # Warnings
 /// This internal type makes no guarantees of compatibility or even api
 /// similarity. With the `alloc::boxed::Box` implementation.
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
-pub struct Box<T> {
-    free_mask: usize,
-    chunk: *mut Chunk<T>,
+#[derive(Debug)]
+pub struct Box<T, B: Bitmap = Bitmap64> {
+    cell_offset: usize,
+    chunk: *mut Chunk<T, B>,
     inner: *mut T,
+    /// The `SlabAllocator` summary word tracking this box's owning chunk,
+    /// and the bit within it that flips back on when the box is dropped.
+    summary_word: *const core::cell::Cell<usize>,
+    summary_bit: usize,
 }
 
-impl<T> core::fmt::Display for Box<T>
+impl<T, B: Bitmap> core::fmt::Display for Box<T, B>
 where
     T: core::fmt::Display,
 {
@@ -24,19 +168,19 @@ where
     }
 }
 
-impl<T> AsRef<T> for Box<T> {
+impl<T, B: Bitmap> AsRef<T> for Box<T, B> {
     fn as_ref(&self) -> &T {
         unsafe { self.inner.as_ref().unwrap() }
     }
 }
 
-impl<T> AsMut<T> for Box<T> {
+impl<T, B: Bitmap> AsMut<T> for Box<T, B> {
     fn as_mut(&mut self) -> &mut T {
         unsafe { self.inner.as_mut().unwrap() }
     }
 }
 
-impl<T> PartialEq<T> for Box<T>
+impl<T, B: Bitmap> PartialEq<T> for Box<T, B>
 where
     T: PartialEq,
 {
@@ -45,9 +189,27 @@ where
     }
 }
 
-impl<T> Eq for Box<T> where T: Eq {}
+impl<T, B: Bitmap> PartialEq for Box<T, B>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<T, B: Bitmap> Eq for Box<T, B> where T: Eq {}
+
+impl<T, B: Bitmap> PartialOrd for Box<T, B>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.as_ref().partial_cmp(other.as_ref())
+    }
+}
 
-impl<T> core::ops::Deref for Box<T> {
+impl<T, B: Bitmap> core::ops::Deref for Box<T, B> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
@@ -55,79 +217,260 @@ impl<T> core::ops::Deref for Box<T> {
     }
 }
 
-impl<T> core::ops::DerefMut for Box<T> {
+impl<T, B: Bitmap> core::ops::DerefMut for Box<T, B> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.as_mut()
     }
 }
 
-impl<T> Drop for Box<T> {
+impl<T, B: Bitmap> Drop for Box<T, B> {
     fn drop(&mut self) {
-        let chunk = unsafe { self.chunk.as_mut() }.expect("chunk couldn't be borrowed");
-
-        chunk.free_list |= self.free_mask;
+        // Borrowed immutably; the free-list lives behind a `Cell` so that
+        // dropping a `Box` never needs to materialize a `&mut Chunk<T, B>`
+        // from a raw pointer that may be aliased by sibling boxes in the
+        // same chunk.
+        let chunk = unsafe { self.chunk.as_ref() }.expect("chunk couldn't be borrowed");
+
+        chunk.poison_cell(self.cell_offset);
+        chunk.dealloc_bit(self.cell_offset);
+
+        // SAFETY: `summary_word` points at a heap-allocated word owned by
+        // the `SlabAllocator` this box came from, which outlives the box.
+        let word =
+            unsafe { self.summary_word.as_ref() }.expect("summary word couldn't be borrowed");
+        word.set(summary_set_bit(word.get(), self.summary_bit));
     }
 }
 
-/// Chunk is a typed segment of memory consisting of a fixed number of cells
-/// represented by the bit-width of the architectures pointer type. The Chunk
-/// handles tracking allocation of cells.
-#[derive(Debug)]
-pub struct Chunk<T> {
-    free_list: usize,
-    inner: [T; usize::BITS as usize],
+/// The sentinel pattern stamped over a cell's bytes when it is freed, behind
+/// the `debug-alloc` feature. A recycled cell that no longer holds this
+/// pattern was written to through a dangling pointer after being freed.
+#[cfg(feature = "debug-alloc")]
+const POISON_PATTERN: u32 = 0xCAFEBABE;
+
+/// The guard word placed immediately before and after each cell's storage
+/// behind the `debug-alloc` feature. A guard that no longer holds this value
+/// means a write overran the cell it belongs to.
+#[cfg(feature = "debug-alloc")]
+const GUARD_PATTERN: usize = 0xDEADBEEF;
+
+/// A single cell's storage, padded with guard words on either side so an
+/// overrunning write into a neighboring cell can be detected. Only compiled
+/// in under `debug-alloc`; release builds use the tightly packed cell
+/// representation instead.
+#[cfg(feature = "debug-alloc")]
+#[repr(C)]
+struct GuardedCell<T> {
+    pre_guard: usize,
+    value: core::mem::MaybeUninit<T>,
+    post_guard: usize,
+}
+
+#[cfg(feature = "debug-alloc")]
+impl<T> GuardedCell<T> {
+    fn poisoned() -> Self {
+        let mut cell = Self {
+            pre_guard: GUARD_PATTERN,
+            value: core::mem::MaybeUninit::uninit(),
+            post_guard: GUARD_PATTERN,
+        };
+        cell.poison();
+        cell
+    }
+
+    /// Stamps the cell's value bytes with the poison pattern.
+    fn poison(&mut self) {
+        let bytes = self.value.as_mut_ptr() as *mut u8;
+        let pattern = POISON_PATTERN.to_ne_bytes();
+
+        for i in 0..core::mem::size_of::<T>() {
+            unsafe { bytes.add(i).write(pattern[i % pattern.len()]) };
+        }
+    }
+
+    /// True if the cell's bytes still hold the poison pattern untouched.
+    fn is_poisoned(&self) -> bool {
+        let bytes = self.value.as_ptr() as *const u8;
+        let pattern = POISON_PATTERN.to_ne_bytes();
+
+        (0..core::mem::size_of::<T>())
+            .all(|i| unsafe { bytes.add(i).read() } == pattern[i % pattern.len()])
+    }
+
+    /// True if both guard words are untouched.
+    fn guards_intact(&self) -> bool {
+        self.pre_guard == GUARD_PATTERN && self.post_guard == GUARD_PATTERN
+    }
 }
 
-impl<T> Chunk<T> {
-    /// The maximum number of elements in the chunk.
-    const ELEMS: usize = usize::BITS as usize;
+/// Chunk is a typed segment of memory consisting of a fixed number of cells,
+/// tracked by a [`Bitmap`] `B`. The Chunk handles tracking allocation of
+/// cells; `SlabAllocator` is responsible for finding a chunk with space.
+///
+/// Cell storage lives in a `Vec` sized to `B::CAPACITY` once at
+/// construction and never resized afterwards, rather than a `[T; B::CAPACITY]`
+/// array: stable Rust has no way to size an array from a generic type
+/// parameter's associated constant. The effect is the same fixed-capacity,
+/// heap-allocated-once storage an array would give.
+///
+/// The free-list is tracked behind a `Cell` rather than as a bare bitmap so
+/// that it can be flipped through a shared reference. This is what lets
+/// `Box::drop` release a cell without reaching for a `&mut Chunk<T, B>`, and
+/// is what the `allocator-api2` adapter relies on to satisfy `Allocator`'s
+/// `&self` methods.
+pub struct Chunk<T, B: Bitmap = Bitmap64> {
+    free_list: core::cell::Cell<B>,
+    #[cfg(not(feature = "debug-alloc"))]
+    inner: alloc::vec::Vec<core::mem::MaybeUninit<T>>,
+    #[cfg(feature = "debug-alloc")]
+    inner: alloc::vec::Vec<GuardedCell<T>>,
+}
 
+impl<T, B: Bitmap> Chunk<T, B> {
     /// Initializes a new empty chunk.
-    #[allow(clippy::uninit_assumed_init)]
+    #[cfg(not(feature = "debug-alloc"))]
     pub fn new() -> Self {
         use core::mem::MaybeUninit;
 
-        let inner = { unsafe { MaybeUninit::uninit().assume_init() } };
+        // Cells are only ever read through the pointer `write_cell` returns
+        // after initializing them (`cell_ptr` hands out a raw pointer without
+        // reading); `free_list` starts fully free so nothing reads an
+        // uninitialized cell.
+        let inner = (0..B::CAPACITY).map(|_| MaybeUninit::uninit()).collect();
 
         Self {
-            free_list: usize::MAX,
+            free_list: core::cell::Cell::new(B::DEFAULT),
             inner,
         }
     }
 
-    /// Finds the first 1 bit, representing a free cell in the allocator. If
-    /// the chunk is full, None is returned. Otherwise the index into the cell
-    /// is returned.
-    fn first_free(&self) -> Option<usize> {
-        let leading_zeros = self.free_list.leading_zeros() as usize;
+    /// Initializes a new empty chunk, with every cell poisoned and guarded.
+    #[cfg(feature = "debug-alloc")]
+    pub fn new() -> Self {
+        let inner = (0..B::CAPACITY).map(|_| GuardedCell::poisoned()).collect();
 
-        // if all bits are allocated return None
-        if leading_zeros == Self::ELEMS {
-            None
-        } else {
-            Some(leading_zeros)
+        Self {
+            free_list: core::cell::Cell::new(B::DEFAULT),
+            inner,
         }
     }
 
+    /// Finds and marks the first free cell, returning its offset, or `None`
+    /// if the chunk is full.
+    fn alloc_bit(&self) -> Option<usize> {
+        let mut bits = self.free_list.get();
+        let offset = bits.alloc_bits()?;
+        self.free_list.set(bits);
+        Some(offset)
+    }
+
+    /// Marks the cell at `offset` free again.
+    fn dealloc_bit(&self, offset: usize) {
+        let mut bits = self.free_list.get();
+        bits.dealloc_bits(offset);
+        self.free_list.set(bits);
+    }
+
     /// Returns true if no cells have been allocated.
     pub fn empty(&self) -> bool {
-        self.free_list == usize::MAX
+        self.free_list.get().is_empty()
     }
 
     /// Returns true if all cells have been allocated.
     pub fn full(&self) -> bool {
-        self.free_list == usize::MIN
+        self.free_list.get().is_full()
+    }
+
+    /// Writes `value` into the cell at `offset`, returning a pointer to it.
+    /// Under `debug-alloc`, first asserts the cell's guard words are intact
+    /// and that it still holds the poison pattern from when it was freed
+    /// (or initialized).
+    #[cfg(not(feature = "debug-alloc"))]
+    fn write_cell(&mut self, offset: usize, value: T) -> *mut T {
+        self.inner[offset].write(value) as *mut T
+    }
+
+    #[cfg(feature = "debug-alloc")]
+    fn write_cell(&mut self, offset: usize, value: T) -> *mut T {
+        let slot = &mut self.inner[offset];
+        assert!(
+            slot.guards_intact(),
+            "slab: guard bytes around cell {offset} were overwritten; buffer overrun detected"
+        );
+        assert!(
+            slot.is_poisoned(),
+            "slab: cell {offset} was mutated through a dangling pointer after being freed"
+        );
+
+        slot.value = core::mem::MaybeUninit::new(value);
+        slot.value.as_mut_ptr()
+    }
+
+    /// Re-poisons a freed cell's bytes. A no-op outside of `debug-alloc`.
+    #[cfg(not(feature = "debug-alloc"))]
+    fn poison_cell(&self, _offset: usize) {}
+
+    #[cfg(feature = "debug-alloc")]
+    fn poison_cell(&self, offset: usize) {
+        // SAFETY: called from `Box::drop` once the box being freed is the
+        // only outstanding reference to this cell; `inner` isn't behind a
+        // `Cell` so this relies on the same discipline the rest of the slab
+        // already does around raw chunk pointers.
+        let slot = unsafe { &mut *(self.inner.as_ptr().add(offset) as *mut GuardedCell<T>) };
+        assert!(
+            slot.guards_intact(),
+            "slab: guard bytes around cell {offset} were overwritten; buffer overrun detected"
+        );
+        slot.poison();
+    }
+
+    /// Returns a pointer to the backing storage for a given cell offset,
+    /// without regard for whether that cell is currently allocated. Only
+    /// used by the `allocator-api2` adapter, which hands out raw bytes
+    /// rather than typed `Box<T>`s.
+    #[cfg(not(feature = "debug-alloc"))]
+    #[cfg_attr(not(feature = "allocator-api2"), allow(dead_code))]
+    fn cell_ptr(&self, offset: usize) -> *const T {
+        unsafe { (*self.inner.as_ptr().add(offset)).as_ptr() }
+    }
+
+    #[cfg(feature = "debug-alloc")]
+    #[cfg_attr(not(feature = "allocator-api2"), allow(dead_code))]
+    fn cell_ptr(&self, offset: usize) -> *const T {
+        unsafe { (*self.inner.as_ptr().add(offset)).value.as_ptr() }
+    }
+
+    /// The stride, in bytes, between consecutive cells' backing storage.
+    /// Only used by the `allocator-api2` adapter.
+    #[cfg(not(feature = "debug-alloc"))]
+    #[cfg_attr(not(feature = "allocator-api2"), allow(dead_code))]
+    fn cell_stride() -> usize {
+        core::mem::size_of::<T>()
+    }
+
+    #[cfg(feature = "debug-alloc")]
+    #[cfg_attr(not(feature = "allocator-api2"), allow(dead_code))]
+    fn cell_stride() -> usize {
+        core::mem::size_of::<GuardedCell<T>>()
     }
 }
 
-impl<T> Default for Chunk<T> {
+impl<T, B: Bitmap> Default for Chunk<T, B> {
     fn default() -> Self {
         Self::new()
     }
 }
 
 /// Provides a SlabAllocator implementation containing a constantly defined
-/// array of sequential `Chunks` for a type.
+/// array of sequential `Chunks` for a type. The inline array is the fast
+/// path; once it fills, [`reserve`](Self::reserve) can grow the slab with
+/// additional heap-allocated chunks so that allocation never has to fail.
+///
+/// The third type parameter selects the [`Bitmap`] each chunk uses to track
+/// its cells, defaulting to [`Bitmap64`] (this crate's original
+/// `usize::BITS`-cells-per-chunk behavior). Pick a narrower bitmap like
+/// [`Bitmap8`] for small pools that don't need 64 cells per chunk, or a
+/// [`WideBitmap`] for wider fan-out than a single machine word covers.
 ///
 /// # Example
 ///
@@ -141,49 +484,125 @@ impl<T> Default for Chunk<T> {
 ///  assert!(optional_boxed_five.unwrap().as_ref() == &5u8);
 ///
 /// ```
-pub struct SlabAllocator<T, const N: usize> {
-    chunks: [Chunk<T>; N],
+pub struct SlabAllocator<T, const N: usize, B: Bitmap = Bitmap64> {
+    chunks: [Chunk<T, B>; N],
+    /// Chunks allocated past the inline `chunks` array once it fills up.
+    /// Each is individually heap-allocated (rather than living in a single
+    /// contiguous `Vec<Chunk<T, B>>`) so that growing this list never moves
+    /// an already-handed-out chunk: only the `Vec<Box<Chunk<T, B>>>` of
+    /// pointers may reallocate, never the chunks they point to.
+    spill: alloc::vec::Vec<alloc::boxed::Box<Chunk<T, B>>>,
+    /// A second-level bitmap over the chunk list: bit *i* of word *w*
+    /// (counted from the MSB, the same convention `Bitmap` implementations
+    /// use) is set iff chunk `w * usize::BITS + i` has at least one free
+    /// cell. This turns `find_chunk_with_space` into a `leading_zeros`
+    /// lookup per word instead of a `full()` check per chunk. Words are
+    /// individually heap-allocated for the same reason spilled chunks are: a
+    /// `Box<T, B>` holds a raw pointer into its word, which must survive
+    /// later growth.
+    #[allow(clippy::vec_box)]
+    summary: alloc::vec::Vec<alloc::boxed::Box<core::cell::Cell<usize>>>,
 }
 
-impl<T, const N: usize> SlabAllocator<T, N> {
-    /// Represents the maximum number of chunks allowed in the allocator. This
-    /// is equivalent to the number of bits of the pointer type.
-    const CHUNK_MAX: u8 = (usize::BITS as u8 - 1);
-
+impl<T, const N: usize, B: Bitmap> SlabAllocator<T, N, B> {
     /// Initializes a new empty `SlabAllocator<T>`.
     pub fn new() -> Self {
         Self::default()
     }
 
-    /// Allocates a value, returning a box to it.
-    pub fn boxed(&mut self, value: T) -> Option<Box<T>> {
-        let optional_chunk = self.find_chunk_with_space();
-        optional_chunk.map(|offset| {
-            let chunk = self.borrow_chunk_mut(offset).unwrap();
-            // safe to unwrap due to above free space guarantee.
-            let free_cell_offset = chunk.first_free().unwrap();
+    /// The total number of cells currently backed by the slab, across both
+    /// the inline chunks and any grown via [`reserve`](Self::reserve).
+    pub fn capacity(&self) -> usize {
+        self.chunk_count() * B::CAPACITY
+    }
 
-            let cell_ptr = {
-                let cell = &mut chunk.inner[free_cell_offset];
-                *cell = value;
-                cell as *mut T
-            };
+    /// The number of cells currently holding a value.
+    pub fn len(&self) -> usize {
+        (0..self.chunk_count())
+            .filter_map(|offset| self.borrow_chunk(offset))
+            .map(|chunk| B::CAPACITY - chunk.free_list.get().free_count())
+            .sum()
+    }
 
-            (*chunk).free_list &= alloc_mask(free_cell_offset as u8);
+    /// Returns true if no cells currently hold a value.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 
-            Box {
-                free_mask: free_mask(free_cell_offset as u8),
-                chunk: chunk as *mut Chunk<T>,
-                inner: cell_ptr as *mut T,
-            }
+    /// Grows the slab so that at least `additional_cells` more allocations
+    /// can succeed without `boxed`/`try_boxed` ever reporting
+    /// [`AllocError::Exhausted`]. Existing `Box<T>`s remain valid; growth
+    /// only appends new, independently heap-allocated chunks.
+    pub fn reserve(&mut self, additional_cells: usize) {
+        let additional_chunks = additional_cells.div_ceil(B::CAPACITY);
+
+        self.spill.reserve(additional_chunks);
+        for _ in 0..additional_chunks {
+            self.spill.push(alloc::boxed::Box::new(Chunk::<T, B>::new()));
+
+            let new_chunk_offset = self.chunk_count() - 1;
+            self.ensure_summary_capacity(new_chunk_offset);
+            self.set_summary_bit(new_chunk_offset, true);
+        }
+    }
+
+    /// The number of chunks currently backing the slab, inline and spilled.
+    fn chunk_count(&self) -> usize {
+        N + self.spill.len()
+    }
+
+    /// Allocates a value, returning a box to it. Returns `None` if the slab
+    /// has no free cells; see [`try_boxed`](Self::try_boxed) for a variant
+    /// that reports why the allocation failed.
+    pub fn boxed(&mut self, value: T) -> Option<Box<T, B>> {
+        self.try_boxed(value).ok()
+    }
+
+    /// Allocates a value, returning a box to it, or an [`AllocError`]
+    /// describing why the allocation couldn't be satisfied.
+    pub fn try_boxed(&mut self, value: T) -> Result<Box<T, B>, AllocError> {
+        let offset = self.find_chunk_with_space().ok_or(AllocError::Exhausted)?;
+        let (summary_word, summary_bit) = self.summary_location(offset);
+
+        let chunk = self.borrow_chunk_mut(offset).unwrap();
+        // safe to unwrap due to above free space guarantee.
+        let cell_offset = chunk.alloc_bit().unwrap();
+
+        let cell_ptr = chunk.write_cell(cell_offset, value);
+
+        if chunk.full() {
+            // SAFETY: `summary_word` points at a heap-allocated word owned
+            // by `self.summary`, which outlives this call.
+            let word = unsafe { &*summary_word };
+            word.set(summary_clear_bit(word.get(), summary_bit));
+        }
+
+        Ok(Box {
+            cell_offset,
+            chunk: chunk as *mut Chunk<T, B>,
+            inner: cell_ptr,
+            summary_word,
+            summary_bit,
         })
     }
 
-    /// finds the first free chunk.
+    /// Finds a chunk with at least one free cell by scanning the summary
+    /// bitmap's words rather than each chunk's own free-list: O(1) per word
+    /// via `leading_zeros`, and O(chunk_count / usize::BITS) words overall.
+    /// A chunk is still found in a single `leading_zeros` lookup whenever
+    /// the whole slab fits in one summary word (`chunk_count <= usize::BITS`).
     fn find_chunk_with_space(&self) -> Option<usize> {
-        for chunk_offset in 0..(Self::CHUNK_MAX as usize) {
-            let chunk = self.borrow_chunk(chunk_offset)?;
-            if !chunk.full() {
+        let chunk_count = self.chunk_count();
+        let word_bits = usize::BITS as usize;
+
+        for (word_idx, word) in self.summary.iter().enumerate() {
+            let bits = word.get();
+            if bits == 0 {
+                continue;
+            }
+
+            let chunk_offset = word_idx * word_bits + bits.leading_zeros() as usize;
+            if chunk_offset < chunk_count {
                 return Some(chunk_offset);
             }
         }
@@ -191,44 +610,120 @@ impl<T, const N: usize> SlabAllocator<T, N> {
         None
     }
 
+    /// Locates the summary word and bit position tracking `chunk_offset`.
+    fn summary_location(&self, chunk_offset: usize) -> (*const core::cell::Cell<usize>, usize) {
+        let word_bits = usize::BITS as usize;
+        let word_idx = chunk_offset / word_bits;
+        let bit_idx = chunk_offset % word_bits;
+
+        let word = self
+            .summary
+            .get(word_idx)
+            .expect("summary word missing for a known chunk offset");
+
+        (
+            alloc::boxed::Box::as_ref(word) as *const core::cell::Cell<usize>,
+            bit_idx,
+        )
+    }
+
+    /// Grows the summary bitmap with zeroed words until it has one covering
+    /// `chunk_offset`.
+    fn ensure_summary_capacity(&mut self, chunk_offset: usize) {
+        let word_bits = usize::BITS as usize;
+        let needed_words = chunk_offset / word_bits + 1;
+
+        while self.summary.len() < needed_words {
+            self.summary
+                .push(alloc::boxed::Box::new(core::cell::Cell::new(0)));
+        }
+    }
+
+    /// Sets or clears the summary bit for `chunk_offset`.
+    fn set_summary_bit(&self, chunk_offset: usize, has_space: bool) {
+        let (word_ptr, bit_idx) = self.summary_location(chunk_offset);
+        // SAFETY: `word_ptr` points at a heap-allocated word owned by
+        // `self.summary`, which outlives this call.
+        let word = unsafe { &*word_ptr };
+
+        if has_space {
+            word.set(summary_set_bit(word.get(), bit_idx));
+        } else {
+            word.set(summary_clear_bit(word.get(), bit_idx));
+        }
+    }
+
     /// Borrows a chunk determined by a given offset. This value must be less
-    /// than the Slab's max chunk count.
-    fn borrow_chunk(&self, offset: usize) -> Option<&Chunk<T>> {
-        self.chunks.get(offset)
+    /// than the Slab's current chunk count, spanning both the inline and
+    /// spilled chunks.
+    fn borrow_chunk(&self, offset: usize) -> Option<&Chunk<T, B>> {
+        match self.chunks.get(offset) {
+            Some(chunk) => Some(chunk),
+            None => self.spill.get(offset - N).map(alloc::boxed::Box::as_ref),
+        }
     }
 
     /// Borrows a chunk determined by a given offset. This value must be less
-    /// than the Slab's max chunk count.
-    fn borrow_chunk_mut(&mut self, offset: usize) -> Option<&mut Chunk<T>> {
-        self.chunks.get_mut(offset)
+    /// than the Slab's current chunk count, spanning both the inline and
+    /// spilled chunks.
+    fn borrow_chunk_mut(&mut self, offset: usize) -> Option<&mut Chunk<T, B>> {
+        match self.chunks.get_mut(offset) {
+            Some(chunk) => Some(chunk),
+            None => self
+                .spill
+                .get_mut(offset - N)
+                .map(alloc::boxed::Box::as_mut),
+        }
     }
 }
 
-#[allow(clippy::zero_ptr)]
-impl<T, const N: usize> Default for SlabAllocator<T, N> {
-    #[allow(clippy::uninit_assumed_init)]
+impl<T, const N: usize, B: Bitmap> Default for SlabAllocator<T, N, B> {
     fn default() -> Self {
-        use core::mem::MaybeUninit;
+        let chunks: [Chunk<T, B>; N] = core::array::from_fn(|_| Chunk::<T, B>::default());
+
+        let word_bits = usize::BITS as usize;
+        let word_count = N.div_ceil(word_bits);
+        let mut summary = alloc::vec::Vec::with_capacity(word_count);
+        let mut remaining_chunks = N;
+        for _ in 0..word_count {
+            let valid_in_word = remaining_chunks.min(word_bits);
+            remaining_chunks -= valid_in_word;
+            summary.push(alloc::boxed::Box::new(core::cell::Cell::new(
+                leading_set_bits(valid_in_word),
+            )));
+        }
 
-        let mut chunks: [Chunk<T>; N] = { unsafe { MaybeUninit::uninit().assume_init() } };
-        for chunk in chunks.iter_mut() {
-            *chunk = Chunk::<T>::default();
+        Self {
+            chunks,
+            spill: alloc::vec::Vec::new(),
+            summary,
         }
+    }
+}
 
-        Self { chunks }
+/// A word with its leading `n` bits set and the rest clear, used to seed a
+/// summary word where only the first `n` of its `usize::BITS` chunks
+/// actually exist.
+const fn leading_set_bits(n: usize) -> usize {
+    if n == 0 {
+        0
+    } else if n >= usize::BITS as usize {
+        usize::MAX
+    } else {
+        !(usize::MAX >> n)
     }
 }
 
-/// Generates a mask for a given position used to assign an allocation to a chunk.
-const fn alloc_mask(pos: u8) -> usize {
-    let shift = ((usize::BITS - 1) as usize) - pos as usize;
-    usize::MAX ^ (1 << shift)
+/// Clears bit `index` (counted from the MSB) of a summary word.
+const fn summary_clear_bit(word: usize, index: usize) -> usize {
+    let shift = (usize::BITS as usize - 1) - index;
+    word & !(1 << shift)
 }
 
-/// Generates the mask for a given postion to free an allocation on a chunk.
-const fn free_mask(pos: u8) -> usize {
-    let shift = ((usize::BITS - 1) as usize) - pos as usize;
-    !usize::MAX ^ (1 << shift)
+/// Sets bit `index` (counted from the MSB) of a summary word.
+const fn summary_set_bit(word: usize, index: usize) -> usize {
+    let shift = (usize::BITS as usize - 1) - index;
+    word | (1 << shift)
 }
 
 #[cfg(test)]
@@ -242,54 +737,139 @@ mod tests {
         let optional_boxed_five = slab.boxed(5);
 
         assert_eq!(
-            Some(usize::MAX >> 1),
-            slab.borrow_chunk(0).map(|chunk| chunk.free_list)
+            Some(u64::MAX >> 1),
+            slab.borrow_chunk(0).map(|chunk| chunk.free_list.get())
         );
         assert_eq!(Some(5), optional_boxed_five.map(|boxed| *boxed));
 
         // check freed after drop
         assert_eq!(
-            Some(usize::MAX),
-            slab.borrow_chunk(0).map(|chunk| chunk.free_list)
+            Some(u64::MAX),
+            slab.borrow_chunk(0).map(|chunk| chunk.free_list.get())
         );
     }
 
+    #[test]
+    #[cfg(feature = "debug-alloc")]
+    fn should_panic_on_reused_cell_corrupted_after_free() {
+        let mut slab = SlabAllocator::<u8, 1>::new();
+        let mut boxed = slab.boxed(5).unwrap();
+        let cell_ptr: *mut u8 = &mut *boxed as *mut u8;
+        core::mem::drop(boxed);
+
+        // Simulate a write through a dangling pointer after the cell was
+        // freed; the poison pattern it was stamped with is now gone.
+        unsafe { cell_ptr.write(0) };
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| slab.boxed(1)));
+        assert!(result.is_err());
+    }
+
     #[test]
     fn should_safely_drop_multiple_allocations() {
         let mut slab = SlabAllocator::<u8, 1>::new();
-        let boxed_values: alloc::vec::Vec<_> = (0..usize::BITS as u8)
-            .into_iter()
+        let boxed_values: alloc::vec::Vec<_> = (0..u64::BITS as u8)
             .map(|x| slab.boxed(x))
             .collect();
 
-        assert_eq!(Some(0), slab.borrow_chunk(0).map(|chunk| chunk.free_list));
+        assert_eq!(
+            Some(0),
+            slab.borrow_chunk(0).map(|chunk| chunk.free_list.get())
+        );
 
         core::mem::drop(boxed_values);
         assert_eq!(
-            Some(usize::MAX),
-            slab.borrow_chunk(0).map(|chunk| chunk.free_list)
+            Some(u64::MAX),
+            slab.borrow_chunk(0).map(|chunk| chunk.free_list.get())
+        );
+    }
+
+    #[test]
+    fn should_report_exhausted_once_full() {
+        let mut slab = SlabAllocator::<u8, 1>::new();
+        let _boxed_values: alloc::vec::Vec<_> = (0..u64::BITS as u8)
+            .map(|x| slab.try_boxed(x).unwrap())
+            .collect();
+
+        assert_eq!(Err(AllocError::Exhausted), slab.try_boxed(0));
+    }
+
+    #[test]
+    fn should_grow_past_initial_capacity_via_reserve() {
+        let mut slab = SlabAllocator::<u8, 1>::new();
+        assert_eq!(u64::BITS as usize, slab.capacity());
+
+        let _filled: alloc::vec::Vec<_> = (0..u64::BITS as u8)
+            .map(|x| slab.try_boxed(x).unwrap())
+            .collect();
+        assert_eq!(Err(AllocError::Exhausted), slab.try_boxed(0));
+
+        slab.reserve(1);
+        assert_eq!((u64::BITS as usize) * 2, slab.capacity());
+
+        let extra = slab.try_boxed(42).unwrap();
+        assert_eq!(u64::BITS as usize + 1, slab.len());
+        assert_eq!(42, *extra);
+    }
+
+    #[test]
+    fn should_skip_full_chunks_via_summary_bitmap() {
+        let mut slab = SlabAllocator::<u8, 2>::new();
+        let _filled_first_chunk: alloc::vec::Vec<_> = (0..u64::BITS as u8)
+            .map(|x| slab.try_boxed(x).unwrap())
+            .collect();
+
+        // chunk 0 is now full; the summary bitmap should route this
+        // allocation straight to chunk 1 rather than scanning chunk 0 again.
+        let boxed = slab.try_boxed(1).unwrap();
+        assert_eq!(
+            Some(0),
+            slab.borrow_chunk(0).map(|chunk| chunk.free_list.get())
+        );
+        assert_eq!(
+            Some(u64::MAX >> 1),
+            slab.borrow_chunk(1).map(|chunk| chunk.free_list.get())
         );
+        assert_eq!(1, *boxed);
     }
 
     #[test]
     fn should_allow_allocations_over_multiple_chunks() {
         let mut slab = SlabAllocator::<u8, 2>::new();
-        let boxed_values: alloc::vec::Vec<_> = (0..(usize::BITS * 2) as u8)
-            .into_iter()
+        let boxed_values: alloc::vec::Vec<_> = (0..(u64::BITS * 2) as u8)
             .map(|x| slab.boxed(x))
             .collect();
 
-        assert_eq!(Some(0), slab.borrow_chunk(0).map(|chunk| chunk.free_list));
-        assert_eq!(Some(0), slab.borrow_chunk(1).map(|chunk| chunk.free_list));
+        assert_eq!(
+            Some(0),
+            slab.borrow_chunk(0).map(|chunk| chunk.free_list.get())
+        );
+        assert_eq!(
+            Some(0),
+            slab.borrow_chunk(1).map(|chunk| chunk.free_list.get())
+        );
 
         core::mem::drop(boxed_values);
         assert_eq!(
-            Some(usize::MAX),
-            slab.borrow_chunk(0).map(|chunk| chunk.free_list)
+            Some(u64::MAX),
+            slab.borrow_chunk(0).map(|chunk| chunk.free_list.get())
         );
         assert_eq!(
-            Some(usize::MAX),
-            slab.borrow_chunk(1).map(|chunk| chunk.free_list)
+            Some(u64::MAX),
+            slab.borrow_chunk(1).map(|chunk| chunk.free_list.get())
         );
     }
+
+    #[test]
+    fn should_allow_a_narrow_bitmap_for_tiny_pools() {
+        let mut slab = SlabAllocator::<u8, 1, Bitmap8>::new();
+        assert_eq!(8, slab.capacity());
+
+        let boxed_values: alloc::vec::Vec<_> =
+            (0..8u8).map(|x| slab.try_boxed(x).unwrap()).collect();
+        assert_eq!(Err(AllocError::Exhausted), slab.try_boxed(0));
+
+        core::mem::drop(boxed_values);
+        assert_eq!(0, slab.len());
+    }
 }